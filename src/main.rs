@@ -1,17 +1,20 @@
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
 use std::env;
 use std::error::Error;
 use std::fs;
 use std::io;
 use std::io::Write;
 use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 // state of current
 struct State<'a> {
     chosen: Option<&'a String>,
     attempts: u64,
     max_attempts: u64,
+    history: Vec<Vec<Match>>,
 }
 
 impl<'a> State<'a> {
@@ -20,31 +23,67 @@ impl<'a> State<'a> {
             chosen: None,
             attempts: 0 as u64,
             max_attempts,
+            history: Vec::new(),
         }
     }
 
     fn reset(&mut self) {
         self.chosen = None;
         self.attempts = 0 as u64;
+        self.history.clear();
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum Match {
     FULL, // letter exists and in correct index
     HALF, // letter exists but in other index
     NONE, // letter don't exists
 }
 
-fn load_words(content: String) -> Vec<String> {
+fn load_words(content: String, word_length: usize) -> Vec<String> {
     content
         .split("\n")
-        .filter(|w| w.len() == 5 && w.chars().all(char::is_alphabetic))
+        .filter(|w| w.chars().count() == word_length && w.chars().all(char::is_alphabetic))
         .map(|w| w.to_uppercase())
         .collect()
 }
 
-fn input_guess(attempt_no: u64) -> Result<String, Box<dyn Error>> {
+// Levenshtein edit distance between `a` and `b`, computed with a single
+// rolling row of size len(b)+1 rather than a full m*n matrix.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, a_ch) in a.chars().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+
+        for (j, b_ch) in b_chars.iter().enumerate() {
+            let temp = row[j + 1];
+            row[j + 1] = if a_ch == *b_ch {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j + 1])
+            };
+            prev_diag = temp;
+        }
+    }
+
+    row[b_chars.len()]
+}
+
+// Finds the dictionary word closest to `guess` by edit distance, to hint the
+// player toward a real word when their guess isn't in the list.
+fn nearest_word<'a>(guess: &str, words: &'a [String]) -> Option<&'a String> {
+    words.iter().min_by_key(|word| edit_distance(guess, word))
+}
+
+fn input_guess(
+    attempt_no: u64,
+    word_length: usize,
+    words: &[String],
+) -> Result<String, Box<dyn Error>> {
     loop {
         let mut input = String::new();
 
@@ -56,10 +95,15 @@ fn input_guess(attempt_no: u64) -> Result<String, Box<dyn Error>> {
 
         if !input.chars().all(char::is_alphabetic) {
             println!("INFO: Word should contain only alphabets");
-        } else if input.len() != 5 {
-            println!("INFO: Must provide word of length 5");
+        } else if input.chars().count() != word_length {
+            println!("INFO: Must provide word of length {}", word_length);
+        } else if !words.contains(&input) {
+            match nearest_word(&input, words) {
+                Some(suggestion) => println!("Not in word list - did you mean {}?", suggestion),
+                None => println!("Not in word list"),
+            }
         } else {
-            return Ok(input.to_uppercase());
+            return Ok(input);
         }
     }
 }
@@ -68,28 +112,119 @@ fn random_word<'a>(words: &'a Vec<String>) -> Option<&'a String> {
     words.choose(&mut thread_rng())
 }
 
-fn evaluate_guess(state: &mut State, guess: &String) -> Option<([Match; 5], u8)> {
-    let chosen = state.chosen.as_ref()?;
-    let mut matches = [Match::NONE; 5];
-    let mut full_match_count: u8 = 0;
+// Converts a day count since the Unix epoch into a "YYYY-MM-DD" UTC date
+// string, using Howard Hinnant's civil_from_days algorithm.
+fn civil_date_from_days(z: i64) -> String {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = if m <= 2 { y + 1 } else { y };
 
-    for (i, (guess_ch, chosen_ch)) in guess.chars().zip(chosen.chars()).enumerate() {
-        if guess_ch == chosen_ch {
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+fn today_utc_date_string() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    civil_date_from_days((seconds / 86400) as i64)
+}
+
+// FNV-1a, fixed and version-independent (unlike std's DefaultHasher), so the
+// same date string always hashes the same way across builds and machines.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// Picks today's deterministic word: sorts the dictionary for a stable index,
+// then seeds a PRNG from a hash of the UTC date so every player (and every
+// replay of the same day) lands on the same index.
+fn daily_word<'a>(words: &'a [String]) -> Option<&'a String> {
+    let mut indices: Vec<usize> = (0..words.len()).collect();
+    indices.sort_by(|&a, &b| words[a].cmp(&words[b]));
+
+    let date_string = today_utc_date_string();
+    let seed = fnv1a_hash(date_string.as_bytes());
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let idx = indices[rng.gen_range(0..indices.len())];
+    words.get(idx)
+}
+
+// Maps 'A'..='Z' to a 0..26 tally index; any other char (non-ASCII letters
+// included) has no tally slot and is treated as never occurring.
+fn letter_index(ch: char) -> Option<usize> {
+    if ch.is_ascii_uppercase() {
+        Some((ch as u8 - b'A') as usize)
+    } else {
+        None
+    }
+}
+
+// Pure scoring function shared by the normal game loop and the solver: marks
+// FULL/HALF/NONE for `guess` against `answer`, capping HALF/FULL per letter at
+// that letter's count in `answer` so repeated guess letters aren't over-matched.
+fn score(guess: &str, answer: &str) -> Vec<Match> {
+    let mut matches = vec![Match::NONE; answer.chars().count()];
+
+    let mut tally = [0u8; 26];
+    for ch in answer.chars() {
+        if let Some(idx) = letter_index(ch) {
+            tally[idx] += 1;
+        }
+    }
+
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let answer_chars: Vec<char> = answer.chars().collect();
+
+    for i in 0..guess_chars.len() {
+        if guess_chars[i] == answer_chars[i] {
             matches[i] = Match::FULL;
-            full_match_count += 1;
-        } else if chosen.contains(guess_ch) {
-            matches[i] = Match::HALF;
-        } else {
-            matches[i] = Match::NONE;
+            if let Some(idx) = letter_index(guess_chars[i]) {
+                tally[idx] -= 1;
+            }
+        }
+    }
+
+    for i in 0..guess_chars.len() {
+        if let Match::FULL = matches[i] {
+            continue;
         }
+        matches[i] = match letter_index(guess_chars[i]) {
+            Some(idx) if tally[idx] > 0 => {
+                tally[idx] -= 1;
+                Match::HALF
+            }
+            _ => Match::NONE,
+        };
     }
 
+    matches
+}
+
+fn evaluate_guess(state: &mut State, guess: &String) -> Option<(Vec<Match>, u8)> {
+    let chosen = state.chosen.as_ref()?;
+    let matches = score(guess, chosen);
+    let full_match_count = matches.iter().filter(|m| matches!(m, Match::FULL)).count() as u8;
+
     state.attempts += 1;
 
     Some((matches, full_match_count))
 }
 
-fn format_match(guess: &String, match_result: [Match; 5]) -> String {
+fn format_match(guess: &String, match_result: &[Match]) -> String {
     let mut segments = Vec::new();
 
     for (mtype, ch) in match_result.iter().zip(guess.chars()) {
@@ -105,6 +240,159 @@ fn format_match(guess: &String, match_result: [Match; 5]) -> String {
     segments.join(" ")
 }
 
+// Encodes a feedback pattern as a base-3 integer, one digit per position:
+// NONE=0, HALF=1, FULL=2. Used to bucket candidates by how they'd score
+// against a given guess.
+fn pattern_code(matches: &[Match]) -> u32 {
+    let mut code: u32 = 0;
+    for mtype in matches.iter() {
+        let digit = match mtype {
+            Match::NONE => 0,
+            Match::HALF => 1,
+            Match::FULL => 2,
+        };
+        code = code * 3 + digit;
+    }
+    code
+}
+
+// Parses solver feedback like "GYBBY" (Green/Yellow/Black per position) into
+// a Vec<Match>, or None if the input isn't exactly `word_length` G/Y/B characters.
+fn parse_feedback(input: &str, word_length: usize) -> Option<Vec<Match>> {
+    let chars: Vec<char> = input.trim().to_uppercase().chars().collect();
+    if chars.len() != word_length {
+        return None;
+    }
+
+    let mut matches = vec![Match::NONE; word_length];
+    for (i, ch) in chars.iter().enumerate() {
+        matches[i] = match ch {
+            'G' => Match::FULL,
+            'Y' => Match::HALF,
+            'B' => Match::NONE,
+            _ => return None,
+        };
+    }
+    Some(matches)
+}
+
+// Shannon entropy (in bits) of the feedback-pattern distribution `guess`
+// would produce across `candidates`. Higher entropy means the guess is
+// expected to split the candidate set more evenly.
+fn guess_entropy(guess: &str, candidates: &[String], word_length: usize) -> f64 {
+    let mut buckets = vec![0u32; 3usize.pow(word_length as u32)];
+    for candidate in candidates {
+        let code = pattern_code(&score(guess, candidate));
+        buckets[code as usize] += 1;
+    }
+
+    let total = candidates.len() as f64;
+    buckets
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+// Ranks every word in `words` by the entropy it would produce against the
+// remaining `candidates`, returning the `top_n` best guesses (ties broken
+// toward words still in `candidates`).
+fn best_guesses(
+    words: &[String],
+    candidates: &[String],
+    word_length: usize,
+    top_n: usize,
+) -> Vec<(String, f64)> {
+    let mut ranked: Vec<(String, f64)> = words
+        .iter()
+        .map(|word| (word.clone(), guess_entropy(word, candidates, word_length)))
+        .collect();
+
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap()
+            .then_with(|| candidates.contains(&b.0).cmp(&candidates.contains(&a.0)))
+    });
+
+    ranked.truncate(top_n);
+    ranked
+}
+
+fn input_feedback(word_length: usize) -> Result<Vec<Match>, Box<dyn Error>> {
+    loop {
+        let mut input = String::new();
+        print!("Feedback (e.g. GYBBY): ");
+        io::stdout().flush()?;
+        io::stdin().read_line(&mut input)?;
+
+        match parse_feedback(&input, word_length) {
+            Some(matches) => return Ok(matches),
+            None => println!("INFO: Feedback must be {} characters of G/Y/B", word_length),
+        }
+    }
+}
+
+fn input_solver_guess(word_length: usize) -> Result<String, Box<dyn Error>> {
+    loop {
+        let mut input = String::new();
+        print!("Your guess: ");
+        io::stdout().flush()?;
+        io::stdin().read_line(&mut input)?;
+
+        let input = input.trim().to_uppercase();
+
+        if !input.chars().all(|ch| ch.is_ascii_alphabetic()) {
+            println!("INFO: Word should contain only ASCII alphabets");
+        } else if input.chars().count() != word_length {
+            println!("INFO: Must provide word of length {}", word_length);
+        } else {
+            return Ok(input);
+        }
+    }
+}
+
+fn run_solver(words: &Vec<String>, word_length: usize) {
+    let mut candidates: Vec<String> = words.clone();
+
+    loop {
+        println!("\n{} candidates remaining", candidates.len());
+
+        if candidates.len() <= 1 {
+            if let Some(word) = candidates.first() {
+                println!("The word is: {}", word);
+            }
+            break;
+        }
+
+        let suggestions = best_guesses(words, &candidates, word_length, 5);
+        println!("Top suggestions:");
+        for (word, entropy) in &suggestions {
+            println!("  {} (entropy {:.3})", word, entropy);
+        }
+
+        let guess = input_solver_guess(word_length).unwrap_or_else(|err| {
+            eprintln!("Error while taking input: {}", err);
+            process::exit(1);
+        });
+
+        let feedback = input_feedback(word_length).unwrap_or_else(|err| {
+            eprintln!("Error while taking input: {}", err);
+            process::exit(1);
+        });
+
+        if feedback.iter().all(|m| matches!(m, Match::FULL)) {
+            println!("Solved: {}", guess);
+            break;
+        }
+
+        let feedback_code = pattern_code(&feedback);
+        candidates.retain(|candidate| pattern_code(&score(&guess, candidate)) == feedback_code);
+    }
+}
+
 fn playagain() -> Result<bool, Box<dyn Error>> {
     loop {
         let mut input = String::new();
@@ -119,21 +407,193 @@ fn playagain() -> Result<bool, Box<dyn Error>> {
     }
 }
 
+// One finished game, as appended to the stats file: "WON|attempts|answer"
+// or "LOST|attempts|answer", one record per line.
+struct GameRecord {
+    won: bool,
+    attempts: u64,
+    answer: String,
+}
+
+fn stats_file_path() -> std::path::PathBuf {
+    let home = env::var("HOME").unwrap_or_else(|_| String::from("."));
+    std::path::Path::new(&home).join(".local/share/wordle/stats.log")
+}
+
+fn record_game(path: &std::path::Path, record: &GameRecord) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(
+        file,
+        "{}|{}|{}",
+        if record.won { "WON" } else { "LOST" },
+        record.attempts,
+        record.answer
+    )
+}
+
+fn load_stats(path: &std::path::Path) -> Vec<GameRecord> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(_) => return Vec::new(),
+    };
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '|');
+            let won = match parts.next()? {
+                "WON" => true,
+                "LOST" => false,
+                _ => return None,
+            };
+            let attempts: u64 = parts.next()?.parse().ok()?;
+            let answer = parts.next()?.to_string();
+            Some(GameRecord { won, attempts, answer })
+        })
+        .collect()
+}
+
+// Prints games played, win %, current streak, max streak and a histogram of
+// winning guess counts, mirroring the stats Wordle shows on startup.
+fn print_stats_summary(records: &[GameRecord], max_attempts: u64) {
+    if records.is_empty() {
+        println!("No games played yet.\n");
+        return;
+    }
+
+    let games_played = records.len();
+    let wins = records.iter().filter(|r| r.won).count();
+    let win_pct = 100.0 * wins as f64 / games_played as f64;
+
+    let mut max_streak = 0u64;
+    let mut running_streak = 0u64;
+    for record in records {
+        if record.won {
+            running_streak += 1;
+            max_streak = max_streak.max(running_streak);
+        } else {
+            running_streak = 0;
+        }
+    }
+
+    let mut current_streak = 0u64;
+    for record in records.iter().rev() {
+        if record.won {
+            current_streak += 1;
+        } else {
+            break;
+        }
+    }
+
+    println!("Games played: {}", games_played);
+    println!("Win rate: {:.1}%", win_pct);
+    println!("Current streak: {}", current_streak);
+    println!("Max streak: {}", max_streak);
+
+    println!("Guess distribution:");
+    for attempt in 1..=max_attempts {
+        let count = records
+            .iter()
+            .filter(|r| r.won && r.attempts == attempt)
+            .count();
+        println!("  {}: {} {}", attempt, "#".repeat(count), count);
+    }
+    println!();
+}
+
+fn match_emoji(m: &Match) -> &'static str {
+    match m {
+        Match::FULL => "\u{1F7E9}",
+        Match::HALF => "\u{1F7E8}",
+        Match::NONE => "\u{2B1C}",
+    }
+}
+
+// Renders the finished board as a copy-paste-able emoji grid, the same way
+// Wordle's own share string works: a win shows the attempt it was solved on,
+// a loss shows "X/max" like the real Wordle share format.
+fn render_share(history: &[Vec<Match>], won: bool, attempts_used: u64, max_attempts: u64) -> String {
+    let header = if won {
+        format!("Wordle {}/{}", attempts_used, max_attempts)
+    } else {
+        format!("Wordle X/{}", max_attempts)
+    };
+    let mut lines = vec![header];
+
+    for guess_matches in history {
+        let row: String = guess_matches.iter().map(match_emoji).collect();
+        lines.push(row);
+    }
+
+    lines.join("\n")
+}
+
+const MIN_WORD_LENGTH: usize = 3;
+const MAX_WORD_LENGTH: usize = 12;
+const MIN_MAX_ATTEMPTS: u64 = 1;
+const MAX_MAX_ATTEMPTS: u64 = 26;
+
+fn usage_error() -> ! {
+    eprintln!("Usage: program [--solve] [--daily] [file] [word-length] [max-attempts]");
+    eprintln!(
+        "  word-length must be in {}..={}, max-attempts must be in {}..={}",
+        MIN_WORD_LENGTH, MAX_WORD_LENGTH, MIN_MAX_ATTEMPTS, MAX_MAX_ATTEMPTS
+    );
+    process::exit(1);
+}
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 2 {
-        eprintln!("Usage: program [file]");
-        process::exit(1);
+    let mut args: Vec<String> = env::args().collect();
+    args.remove(0);
+
+    let solve = if let Some(pos) = args.iter().position(|arg| arg == "--solve") {
+        args.remove(pos);
+        true
+    } else {
+        false
+    };
+
+    let daily = if let Some(pos) = args.iter().position(|arg| arg == "--daily") {
+        args.remove(pos);
+        true
+    } else {
+        false
+    };
+
+    if args.len() > 3 {
+        usage_error();
     }
 
-    let source_file = if args.len() == 2 {
-        args[1].clone()
+    let source_file = if !args.is_empty() {
+        args[0].clone()
     } else {
         String::from("data/words5.txt")
     };
 
+    let word_length: usize = if args.len() >= 2 {
+        match args[1].parse() {
+            Ok(n) if (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&n) => n,
+            _ => usage_error(),
+        }
+    } else {
+        5
+    };
+
+    let max_attempts: u64 = if args.len() >= 3 {
+        match args[2].parse() {
+            Ok(n) if (MIN_MAX_ATTEMPTS..=MAX_MAX_ATTEMPTS).contains(&n) => n,
+            _ => usage_error(),
+        }
+    } else {
+        6
+    };
+
     let words = match fs::read_to_string(&source_file) {
-        Ok(content) => load_words(content),
+        Ok(content) => load_words(content, word_length),
         Err(e) => {
             eprintln!("Error occured while reading file: {} \n{}", source_file, e);
             process::exit(1);
@@ -145,18 +605,32 @@ fn main() {
         process::exit(1);
     }
 
+    if solve {
+        if daily {
+            eprintln!("Note: --daily has no effect with --solve");
+        }
+        run_solver(&words, word_length);
+        return;
+    }
+
     let wordle = "\x1b[30;41m W \x1b[30;42m O \x1b[30;43m R \x1b[30;44m D \x1b[30;45m L \x1b[30;46m E \x1b[0m";
-    let max_attempts = 6;
+    let stats_path = stats_file_path();
+    print_stats_summary(&load_stats(&stats_path), max_attempts);
+
     let mut state = State::init(max_attempts);
 
     loop {
         if let None = state.chosen {
-            state.chosen = random_word(&words);
+            state.chosen = if daily {
+                daily_word(&words)
+            } else {
+                random_word(&words)
+            };
             state.attempts = 0;
             println!("\n{}\n", wordle);
         }
 
-        let guess = input_guess(state.attempts + 1).unwrap_or_else(|err| {
+        let guess = input_guess(state.attempts + 1, word_length, &words).unwrap_or_else(|err| {
             eprintln!("Error while tking input: {}", err);
             process::exit(1);
         });
@@ -169,9 +643,11 @@ fn main() {
             }
         };
 
-        println!("{}\n", format_match(&guess, matches));
+        println!("{}\n", format_match(&guess, &matches));
+        state.history.push(matches);
 
-        if match_count == 5 {
+        let won = match_count as usize == word_length;
+        if won {
             println!("You WON!");
         } else if state.attempts >= state.max_attempts {
             println!("You LOST!");
@@ -186,6 +662,34 @@ fn main() {
             continue;
         }
 
+        let answer = match state.chosen {
+            Some(word) => word.clone(),
+            None => {
+                eprintln!("Something went wrong: No word is chosen!");
+                process::exit(1);
+            }
+        };
+        if let Err(e) = record_game(
+            &stats_path,
+            &GameRecord {
+                won,
+                attempts: state.attempts,
+                answer,
+            },
+        ) {
+            eprintln!("Warning: failed to save stats: {}", e);
+        }
+
+        println!(
+            "\n{}\n",
+            render_share(&state.history, won, state.attempts, state.max_attempts)
+        );
+
+        if daily {
+            println!("Daily challenge complete for today!");
+            break;
+        }
+
         let keep_playing = playagain().unwrap_or_else(|err| {
             eprintln!("Error occured while reading input: {}", err);
             process::exit(1);
@@ -198,3 +702,53 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_caps_matches_at_letter_count_in_answer() {
+        // "EERIE" has two E's against a chosen word with only two E's: the
+        // two-pass tally must not paint every guessed E as HALF/FULL.
+        assert_eq!(
+            score("EERIE", "WHERE"),
+            vec![Match::HALF, Match::NONE, Match::HALF, Match::NONE, Match::FULL]
+        );
+    }
+
+    #[test]
+    fn score_marks_full_match_for_identical_words() {
+        assert_eq!(
+            score("WORLD", "WORLD"),
+            vec![Match::FULL; 5]
+        );
+    }
+
+    #[test]
+    fn edit_distance_classic_example() {
+        assert_eq!(edit_distance("KITTEN", "SITTING"), 3);
+    }
+
+    #[test]
+    fn edit_distance_identical_words_is_zero() {
+        assert_eq!(edit_distance("WORLD", "WORLD"), 0);
+    }
+
+    #[test]
+    fn civil_date_from_days_epoch() {
+        assert_eq!(civil_date_from_days(0), "1970-01-01");
+    }
+
+    #[test]
+    fn guess_entropy_is_zero_when_all_candidates_score_identically() {
+        let candidates = vec![String::from("AAAAA"), String::from("AAAAA")];
+        assert_eq!(guess_entropy("BBBBB", &candidates, 5), 0.0);
+    }
+
+    #[test]
+    fn guess_entropy_is_positive_when_guess_splits_candidates() {
+        let candidates = vec![String::from("WORLD"), String::from("MOUSE")];
+        assert!(guess_entropy("WORLD", &candidates, 5) > 0.0);
+    }
+}